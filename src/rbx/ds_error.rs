@@ -0,0 +1,13 @@
+//! Error response body returned by the DataStore/OrderedDataStore APIs.
+
+use serde::Deserialize;
+
+/// Body returned by Roblox Open Cloud APIs when a request does not succeed.
+///
+/// `Default` is derived so callers that only have the HTTP status (e.g. a
+/// non-JSON body from a proxy/gateway error) can still produce a value here.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct DataStoreErrorResponse {
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
@@ -0,0 +1,54 @@
+//! Error types returned by the `rbx` API operations.
+
+use std::time::Duration;
+
+use thiserror::Error as ThisError;
+
+use crate::rbx::ds_error::DataStoreErrorResponse;
+
+/// Errors that can occur while calling a Roblox Open Cloud API.
+#[derive(ThisError, Debug)]
+pub enum Error {
+    #[error("request failed: {0}")]
+    Reqwest(#[from] reqwest::Error),
+
+    #[error("failed to (de)serialize request body: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The request was rejected with a status code that doesn't have a more
+    /// specific variant below.
+    #[error("datastore error: {0:?}")]
+    DataStoreError(DataStoreErrorResponse),
+
+    /// `404 Not Found` - the requested entry, datastore, or scope doesn't exist.
+    #[error("entry not found: {0:?}")]
+    NotFound(DataStoreErrorResponse),
+
+    /// `429 Too Many Requests` - back off and retry, optionally honoring
+    /// `retry_after` if Roblox provided one.
+    #[error("rate limited (retry after {retry_after:?}): {body:?}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        body: DataStoreErrorResponse,
+    },
+
+    /// `400 Bad Request` - the request was malformed and retrying as-is won't help.
+    #[error("invalid request: {0:?}")]
+    InvalidRequest(DataStoreErrorResponse),
+
+    /// `401 Unauthorized` / `403 Forbidden` - the API key is missing or lacks
+    /// permission for this operation.
+    #[error("unauthorized: {0:?}")]
+    Unauthorized(DataStoreErrorResponse),
+
+    /// `5xx` - the service is unavailable or errored.
+    #[error("service unavailable: {0:?}")]
+    ServiceUnavailable(DataStoreErrorResponse),
+
+    /// Failed to decode a compressed response body. Only returned when a
+    /// compression feature (e.g. `gzip`) is enabled and the server actually
+    /// sends an encoded body.
+    #[cfg(feature = "gzip")]
+    #[error("failed to decode response body: {0}")]
+    Decompress(#[from] std::io::Error),
+}
@@ -3,8 +3,21 @@
 //! Typically, these operations should be consumed through the `RbxExperience`
 //! struct, obtained through the `RbxCloud` struct.
 //!
+//! With the `gzip` cargo feature enabled, GET requests advertise
+//! `Accept-Encoding: gzip` and any gzip-encoded response body is
+//! transparently decoded before being handed to `serde_json`. This is
+//! opt-in so consumers who don't want the extra dependency can leave it
+//! off; without the feature, responses are parsed exactly as before.
 
-use reqwest::Response;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use futures::stream::{self, Stream, StreamExt};
+use rand::Rng;
+use reqwest::{HeaderMap, RequestBuilder, Response, StatusCode};
 use serde::{de::DeserializeOwned, Deserialize};
 use serde_json::json;
 
@@ -51,20 +64,72 @@ pub struct OrderedIncrementEntryParams {
     pub increment: i64,
 }
 
-#[derive(Deserialize, Debug)]
+pub struct OrderedBatchCreateEntriesParams {
+    pub api_key: String,
+    pub universe_id: UniverseId,
+    pub ordered_datastore_name: String,
+    pub scope: Option<String>,
+    pub entries: Vec<(String, i64)>,
+    /// Maximum number of `create_entry` calls in flight at once.
+    pub concurrency: usize,
+}
+
+pub struct OrderedBatchUpdateEntriesParams {
+    pub api_key: String,
+    pub universe_id: UniverseId,
+    pub ordered_datastore_name: String,
+    pub scope: Option<String>,
+    pub entries: Vec<(String, i64)>,
+    pub allow_missing: Option<bool>,
+    /// Maximum number of `update_entry` calls in flight at once.
+    pub concurrency: usize,
+}
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct OrderedEntry {
     pub path: String,
     pub id: String,
     pub value: f64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderedListEntriesResponse {
     pub entries: Vec<OrderedEntry>,
     pub next_page_token: Option<String>,
 }
 
+/// Pagination state carried between pages by `list_all_entries`: the
+/// entries of the page currently being drained, and whether the last page
+/// fetched was the final one.
+struct PageCursor {
+    page: std::vec::IntoIter<OrderedEntry>,
+    exhausted: bool,
+}
+
+impl PageCursor {
+    fn new() -> Self {
+        Self {
+            page: Vec::new().into_iter(),
+            exhausted: false,
+        }
+    }
+
+    /// Absorb a freshly-fetched page: stash its entries for iteration, and
+    /// mark the cursor exhausted if this was the last page. Returns the
+    /// page token to request next, if there is one.
+    fn apply(&mut self, response: OrderedListEntriesResponse) -> Option<String> {
+        self.page = response.entries.into_iter();
+        match response.next_page_token {
+            Some(token) => Some(token),
+            None => {
+                self.exhausted = true;
+                None
+            }
+        }
+    }
+}
+
 pub struct OrderedEntryParams {
     pub api_key: String,
     pub universe_id: UniverseId,
@@ -73,26 +138,678 @@ pub struct OrderedEntryParams {
     pub id: String,
 }
 
+/// Retry/backoff policy applied to requests that fail with a rate-limit or
+/// transient server error.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of retry attempts after the initial request.
+    pub max_retries: u32,
+    /// Base delay used for the exponential backoff (`base * 2^attempt`).
+    pub base_delay: Duration,
+    /// Upper bound on any computed delay, including jitter.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Whether retrying a failed request is safe. `POST`/`PATCH` operations
+/// (create/update/increment) are not idempotent, so they only retry on
+/// `429`/`503`; `GET`/`DELETE` may retry on any `429` or `5xx`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Idempotency {
+    Safe,
+    Unsafe,
+}
+
+impl Idempotency {
+    fn should_retry(self, status: StatusCode) -> bool {
+        match self {
+            Idempotency::Safe => {
+                status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+            }
+            Idempotency::Unsafe => {
+                status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+            }
+        }
+    }
+}
+
+/// Configuration for the optional client-side read cache.
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// How long a cached entry/list page stays valid.
+    pub ttl: Duration,
+    /// Maximum number of keys held per cache before older entries are
+    /// evicted to make room.
+    pub capacity: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(30),
+            capacity: 1024,
+        }
+    }
+}
+
+// `EntryKey`/`ListKey` use `UniverseId` and `PageSize` as (part of) map
+// keys, so both types must derive `Hash + Eq + Clone` wherever they're
+// defined for this to compile.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct EntryKey {
+    universe_id: UniverseId,
+    ordered_datastore_name: String,
+    scope: Option<String>,
+    id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ListKey {
+    universe_id: UniverseId,
+    ordered_datastore_name: String,
+    scope: Option<String>,
+    max_page_size: Option<PageSize>,
+    page_token: Option<String>,
+    order_by: Option<String>,
+    filter: Option<String>,
+}
+
+impl ListKey {
+    fn matches_store(
+        &self,
+        universe_id: UniverseId,
+        ordered_datastore_name: &str,
+        scope: Option<&str>,
+    ) -> bool {
+        self.universe_id == universe_id
+            && self.ordered_datastore_name == ordered_datastore_name
+            && self.scope.as_deref() == scope
+    }
+}
+
+/// A small bounded TTL cache. Entries past their expiry are treated as
+/// absent and lazily swept out on the next access that touches them.
+struct TtlCache<K, V> {
+    config: CacheConfig,
+    entries: Mutex<HashMap<K, (V, Instant)>>,
+}
+
+impl<K: std::hash::Hash + Eq + Clone, V: Clone> TtlCache<K, V> {
+    fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((value, expires_at)) if *expires_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: K, value: V) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.config.capacity && !entries.contains_key(&key) {
+            if let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, (_, expires_at))| *expires_at)
+                .map(|(k, _)| k.clone())
+            {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(key, (value, Instant::now() + self.config.ttl));
+    }
+
+    fn invalidate(&self, key: &K) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    fn invalidate_matching(&self, predicate: impl Fn(&K) -> bool) {
+        self.entries.lock().unwrap().retain(|k, _| !predicate(k));
+    }
+}
+
+/// Client for the OrderedDataStore API.
+///
+/// Holds a shared `reqwest::Client` (so requests reuse one connection pool),
+/// the [`RetryConfig`] applied when Roblox responds with a rate limit or a
+/// transient server error, and an optional read-through [`CacheConfig`].
+pub struct OrderedDataStore {
+    client: reqwest::Client,
+    retry: RetryConfig,
+    entry_cache: Option<TtlCache<EntryKey, OrderedEntry>>,
+    list_cache: Option<TtlCache<ListKey, OrderedListEntriesResponse>>,
+}
+
+impl OrderedDataStore {
+    /// Create an `OrderedDataStore` using the default retry policy and no
+    /// read cache.
+    pub fn new(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            retry: RetryConfig::default(),
+            entry_cache: None,
+            list_cache: None,
+        }
+    }
+
+    /// Create an `OrderedDataStore` with a custom retry policy.
+    pub fn with_retry_config(client: reqwest::Client, retry: RetryConfig) -> Self {
+        Self {
+            client,
+            retry,
+            entry_cache: None,
+            list_cache: None,
+        }
+    }
+
+    /// Opt into a client-side read cache for `get_entry`/`list_entries`.
+    /// `create_entry`, `update_entry`, `increment_entry`, and `delete_entry`
+    /// invalidate the matching entries so reads never observe stale values
+    /// after a local mutation.
+    pub fn with_cache(mut self, config: CacheConfig) -> Self {
+        self.entry_cache = Some(TtlCache::new(config.clone()));
+        self.list_cache = Some(TtlCache::new(config));
+        self
+    }
+
+    /// List entries of an OrderedDataStore.
+    pub async fn list_entries(
+        &self,
+        params: &OrderedListEntriesParams,
+    ) -> Result<OrderedListEntriesResponse, Error> {
+        let list_key = list_key_for(params);
+        if let Some(cache) = &self.list_cache {
+            if let Some(cached) = cache.get(&list_key) {
+                return Ok(cached);
+            }
+        }
+
+        let url = build_url("/entries", params.universe_id, params.scope.as_deref());
+        let mut query: QueryString = vec![];
+        if let Some(max_page_size) = &params.max_page_size {
+            query.push(("max_page_size", max_page_size.to_string()));
+        }
+        if let Some(page_token) = &params.page_token {
+            query.push(("page_token", page_token.to_string()));
+        }
+        if let Some(order_by) = &params.order_by {
+            query.push(("order_by", order_by.to_string()));
+        }
+        if let Some(filter) = &params.filter {
+            query.push(("filter", filter.to_string()));
+        }
+        let res = self
+            .send_with_retry(Idempotency::Safe, || {
+                accept_encoding(
+                    self.client
+                        .get(&url)
+                        .header("x-api-key", &params.api_key)
+                        .query(&query),
+                )
+            })
+            .await?;
+        let response = handle_res::<OrderedListEntriesResponse>(res).await?;
+
+        if let Some(cache) = &self.list_cache {
+            cache.insert(list_key, response.clone());
+        }
+        Ok(response)
+    }
+
+    /// Stream every entry of an OrderedDataStore, transparently following
+    /// `next_page_token` until the list is exhausted.
+    ///
+    /// This has the same filtering/ordering behavior as `list_entries` (the
+    /// `max_page_size`, `order_by`, and `filter` from `params` are reused for
+    /// every page fetched), but yields individual `OrderedEntry` values
+    /// instead of forcing the caller to manage pagination tokens.
+    pub fn list_all_entries<'a>(
+        &'a self,
+        params: OrderedListEntriesParams,
+    ) -> impl Stream<Item = Result<OrderedEntry, Error>> + 'a {
+        struct State<'a> {
+            store: &'a OrderedDataStore,
+            params: OrderedListEntriesParams,
+            cursor: PageCursor,
+        }
+
+        let state = State {
+            store: self,
+            params,
+            cursor: PageCursor::new(),
+        };
+
+        stream::try_unfold(state, |mut state| async move {
+            loop {
+                if let Some(entry) = state.cursor.page.next() {
+                    return Ok(Some((entry, state)));
+                }
+                if state.cursor.exhausted {
+                    return Ok(None);
+                }
+                let response = state.store.list_entries(&state.params).await?;
+                if let Some(token) = state.cursor.apply(response) {
+                    state.params.page_token = Some(token);
+                }
+            }
+        })
+    }
+
+    /// Add a new entry to an OrderedDataStore.
+    pub async fn create_entry(
+        &self,
+        params: &OrderedCreateEntryParams,
+    ) -> Result<OrderedEntry, Error> {
+        let url = build_url("/entries", params.universe_id, params.scope.as_deref());
+        let query: QueryString = vec![("id", params.id.to_string())];
+        let body_json = json!({
+            "value": &params.value,
+        });
+        let body = serde_json::to_string(&body_json)?;
+        let res = self
+            .send_with_retry(Idempotency::Unsafe, || {
+                self.client
+                    .post(&url)
+                    .header("x-api-key", &params.api_key)
+                    .query(&query)
+                    .body(body.clone())
+            })
+            .await?;
+        let entry = handle_res::<OrderedEntry>(res).await?;
+        self.invalidate_store(
+            params.universe_id,
+            &params.ordered_datastore_name,
+            params.scope.as_deref(),
+            &params.id,
+        );
+        Ok(entry)
+    }
+
+    pub async fn get_entry(&self, params: &OrderedEntryParams) -> Result<OrderedEntry, Error> {
+        let entry_key = entry_key_for(params);
+        if let Some(cache) = &self.entry_cache {
+            if let Some(cached) = cache.get(&entry_key) {
+                return Ok(cached);
+            }
+        }
+
+        let url = build_url(
+            format!("/entries/{entry}", entry = params.id).as_str(),
+            params.universe_id,
+            params.scope.as_deref(),
+        );
+        let res = self
+            .send_with_retry(Idempotency::Safe, || {
+                accept_encoding(self.client.get(&url).header("x-api-key", &params.api_key))
+            })
+            .await?;
+        let entry = handle_res::<OrderedEntry>(res).await?;
+
+        if let Some(cache) = &self.entry_cache {
+            cache.insert(entry_key, entry.clone());
+        }
+        Ok(entry)
+    }
+
+    pub async fn delete_entry(&self, params: &OrderedEntryParams) -> Result<(), Error> {
+        let url = build_url(
+            format!("/entries/{entry}", entry = params.id).as_str(),
+            params.universe_id,
+            params.scope.as_deref(),
+        );
+        let res = self
+            .send_with_retry(Idempotency::Safe, || {
+                self.client
+                    .delete(&url)
+                    .header("x-api-key", &params.api_key)
+            })
+            .await?;
+        handle_res_ok(res).await?;
+        self.invalidate_store(
+            params.universe_id,
+            &params.ordered_datastore_name,
+            params.scope.as_deref(),
+            &params.id,
+        );
+        Ok(())
+    }
+
+    pub async fn update_entry(
+        &self,
+        params: &OrderedUpdateEntryParams,
+    ) -> Result<OrderedEntry, Error> {
+        let url = build_url(
+            format!("/entries/{entry}", entry = params.id).as_str(),
+            params.universe_id,
+            params.scope.as_deref(),
+        );
+        let mut query: QueryString = vec![];
+        if let Some(allow_missing) = &params.allow_missing {
+            query.push(("allow_missing", allow_missing.to_string()));
+        }
+        let body_json = json!({
+            "value": &params.value,
+        });
+        let body = serde_json::to_string(&body_json)?;
+        let res = self
+            .send_with_retry(Idempotency::Unsafe, || {
+                self.client
+                    .patch(&url)
+                    .header("x-api-key", &params.api_key)
+                    .body(body.clone())
+                    .query(&query)
+            })
+            .await?;
+        let entry = handle_res::<OrderedEntry>(res).await?;
+        self.invalidate_store(
+            params.universe_id,
+            &params.ordered_datastore_name,
+            params.scope.as_deref(),
+            &params.id,
+        );
+        Ok(entry)
+    }
+
+    pub async fn increment_entry(
+        &self,
+        params: &OrderedIncrementEntryParams,
+    ) -> Result<OrderedEntry, Error> {
+        let url = build_url(
+            format!("/entries/{entry}:increment", entry = params.id).as_str(),
+            params.universe_id,
+            params.scope.as_deref(),
+        );
+        let body_json = json!({
+            "amount": &params.increment,
+        });
+        let body = serde_json::to_string(&body_json)?;
+        let res = self
+            .send_with_retry(Idempotency::Unsafe, || {
+                self.client
+                    .patch(&url)
+                    .header("x-api-key", &params.api_key)
+                    .body(body.clone())
+            })
+            .await?;
+        let entry = handle_res::<OrderedEntry>(res).await?;
+        self.invalidate_store(
+            params.universe_id,
+            &params.ordered_datastore_name,
+            params.scope.as_deref(),
+            &params.id,
+        );
+        Ok(entry)
+    }
+
+    /// Create many entries concurrently (bounded by `params.concurrency`),
+    /// returning a result per id instead of aborting on the first failure so
+    /// callers can retry only the ids that failed.
+    pub async fn batch_create_entries(
+        &self,
+        params: &OrderedBatchCreateEntriesParams,
+    ) -> Vec<(String, Result<OrderedEntry, Error>)> {
+        stream::iter(params.entries.iter().cloned().map(|(id, value)| {
+            let create_params = OrderedCreateEntryParams {
+                api_key: params.api_key.clone(),
+                universe_id: params.universe_id,
+                ordered_datastore_name: params.ordered_datastore_name.clone(),
+                scope: params.scope.clone(),
+                id,
+                value,
+            };
+            async move {
+                let result = self.create_entry(&create_params).await;
+                (create_params.id, result)
+            }
+        }))
+        .buffer_unordered(params.concurrency.max(1))
+        .collect()
+        .await
+    }
+
+    /// Update many entries concurrently (bounded by `params.concurrency`),
+    /// returning a result per id instead of aborting on the first failure so
+    /// callers can retry only the ids that failed.
+    pub async fn batch_update_entries(
+        &self,
+        params: &OrderedBatchUpdateEntriesParams,
+    ) -> Vec<(String, Result<OrderedEntry, Error>)> {
+        stream::iter(params.entries.iter().cloned().map(|(id, value)| {
+            let update_params = OrderedUpdateEntryParams {
+                api_key: params.api_key.clone(),
+                universe_id: params.universe_id,
+                ordered_datastore_name: params.ordered_datastore_name.clone(),
+                scope: params.scope.clone(),
+                id,
+                value,
+                allow_missing: params.allow_missing,
+            };
+            async move {
+                let result = self.update_entry(&update_params).await;
+                (update_params.id, result)
+            }
+        }))
+        .buffer_unordered(params.concurrency.max(1))
+        .collect()
+        .await
+    }
+
+    /// Invalidate the cached entry for `id`, along with every cached list
+    /// page for this store/scope, so a subsequent read never observes a
+    /// value that a local mutation has already superseded.
+    fn invalidate_store(
+        &self,
+        universe_id: UniverseId,
+        ordered_datastore_name: &str,
+        scope: Option<&str>,
+        id: &str,
+    ) {
+        if let Some(cache) = &self.entry_cache {
+            cache.invalidate(&EntryKey {
+                universe_id,
+                ordered_datastore_name: ordered_datastore_name.to_string(),
+                scope: scope.map(str::to_string),
+                id: id.to_string(),
+            });
+        }
+        if let Some(cache) = &self.list_cache {
+            cache.invalidate_matching(|key| {
+                key.matches_store(universe_id, ordered_datastore_name, scope)
+            });
+        }
+    }
+
+    /// Send a request built by `build`, retrying on rate-limit/transient
+    /// failures according to `self.retry` and `idempotency`.
+    async fn send_with_retry<F>(
+        &self,
+        idempotency: Idempotency,
+        build: F,
+    ) -> Result<Response, Error>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let mut attempt = 0;
+        loop {
+            let res = build().send().await?;
+            let status = res.status();
+            if status.is_success() {
+                return Ok(res);
+            }
+            if attempt >= self.retry.max_retries || !idempotency.should_retry(status) {
+                return Err(classify_error(res).await?);
+            }
+            let delay = retry_after_from_headers(res.headers())
+                .map(|delay| std::cmp::min(delay, self.retry.max_delay))
+                .unwrap_or_else(|| backoff_delay(&self.retry, attempt));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Compute `min(base * 2^attempt, cap)` plus random jitter in `[0, base)`,
+/// with the sum capped at `cap` as well.
+fn backoff_delay(retry: &RetryConfig, attempt: u32) -> Duration {
+    let exp = retry
+        .base_delay
+        .saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX));
+    let capped = std::cmp::min(exp, retry.max_delay);
+    let jitter = Duration::from_millis(
+        rand::thread_rng().gen_range(0..retry.base_delay.as_millis().max(1) as u64),
+    );
+    std::cmp::min(capped + jitter, retry.max_delay)
+}
+
 async fn handle_res<T: DeserializeOwned>(res: Response) -> Result<T, Error> {
     match res.status().is_success() {
         true => {
-            let body = res.json::<T>().await?;
+            let headers = res.headers().clone();
+            let bytes = decode_body(&headers, res.bytes().await?)?;
+            let body = serde_json::from_slice::<T>(&bytes)?;
             Ok(body)
         }
-        false => {
-            let err_res = res.json::<DataStoreErrorResponse>().await?;
-            Err(Error::DataStoreError(err_res))
-        }
+        false => Err(classify_error(res).await?),
     }
 }
 
 async fn handle_res_ok(res: Response) -> Result<(), Error> {
     match res.status().is_success() {
         true => Ok(()),
-        false => {
-            let err_res = res.json::<DataStoreErrorResponse>().await?;
-            Err(Error::DataStoreError(err_res))
-        }
+        false => Err(classify_error(res).await?),
+    }
+}
+
+/// Turn a non-success `Response` into a typed `Error`, so callers can branch
+/// on "not found" vs "rate limited" vs "bad request" etc. without
+/// string-matching the decoded body.
+///
+/// The status code alone decides which `Error` variant comes back; the body
+/// is parsed best-effort and defaults to an empty `DataStoreErrorResponse`
+/// when it isn't valid JSON (a gateway/proxy error page, an empty body,
+/// etc.), so a body that isn't JSON never masks the status-based
+/// classification. A body that fails to decompress (with the `gzip` feature)
+/// is returned as `Error::Decompress` instead.
+async fn classify_error(res: Response) -> Result<Error, Error> {
+    let status = res.status();
+    let retry_after = retry_after_from_headers(res.headers());
+    let headers = res.headers().clone();
+    let bytes = decode_body(&headers, res.bytes().await?)?;
+    let body = serde_json::from_slice::<DataStoreErrorResponse>(&bytes).unwrap_or_default();
+    Ok(match status {
+        StatusCode::NOT_FOUND => Error::NotFound(body),
+        StatusCode::TOO_MANY_REQUESTS => Error::RateLimited { retry_after, body },
+        StatusCode::BAD_REQUEST => Error::InvalidRequest(body),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Error::Unauthorized(body),
+        s if s.is_server_error() => Error::ServiceUnavailable(body),
+        _ => Error::DataStoreError(body),
+    })
+}
+
+/// Parse a retry delay out of a `Retry-After` header, which Roblox sends as
+/// a relative number of seconds to wait.
+///
+/// Falls back to `x-ratelimit-reset`, which (unlike `Retry-After`) is an
+/// absolute Unix timestamp of when the window resets rather than a relative
+/// offset; it's converted to a relative delay here so callers never sleep
+/// for the raw epoch value. The caller is still responsible for capping the
+/// result against `RetryConfig::max_delay` in case either header is
+/// unexpectedly large.
+fn retry_after_from_headers(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(seconds) = headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.parse::<u64>().ok())
+    {
+        return Some(Duration::from_secs(seconds));
+    }
+    headers
+        .get("x-ratelimit-reset")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.parse::<u64>().ok())
+        .and_then(|reset_at| {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .ok()?
+                .as_secs();
+            Some(Duration::from_secs(reset_at.saturating_sub(now)))
+        })
+}
+
+/// Advertise support for compressed responses on GET requests. A no-op
+/// unless the `gzip` feature is enabled.
+fn accept_encoding(builder: RequestBuilder) -> RequestBuilder {
+    #[cfg(feature = "gzip")]
+    {
+        builder.header(reqwest::header::ACCEPT_ENCODING, "gzip")
+    }
+    #[cfg(not(feature = "gzip"))]
+    {
+        builder
+    }
+}
+
+/// Undo gzip compression on a response body if `Content-Encoding: gzip` was
+/// set, so callers can `serde_json` the result as if it arrived uncompressed.
+/// A no-op unless the `gzip` feature is enabled.
+#[cfg(feature = "gzip")]
+fn decode_body(headers: &HeaderMap, bytes: bytes::Bytes) -> Result<bytes::Bytes, Error> {
+    use std::io::Read;
+
+    let is_gzip = headers
+        .get(reqwest::header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.eq_ignore_ascii_case("gzip"))
+        .unwrap_or(false);
+    if !is_gzip {
+        return Ok(bytes);
+    }
+    let mut decoded = Vec::new();
+    flate2::read::GzDecoder::new(&bytes[..]).read_to_end(&mut decoded)?;
+    Ok(bytes::Bytes::from(decoded))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn decode_body(_headers: &HeaderMap, bytes: bytes::Bytes) -> Result<bytes::Bytes, Error> {
+    Ok(bytes)
+}
+
+fn entry_key_for(params: &OrderedEntryParams) -> EntryKey {
+    EntryKey {
+        universe_id: params.universe_id,
+        ordered_datastore_name: params.ordered_datastore_name.clone(),
+        scope: params.scope.clone(),
+        id: params.id.clone(),
+    }
+}
+
+fn list_key_for(params: &OrderedListEntriesParams) -> ListKey {
+    ListKey {
+        universe_id: params.universe_id,
+        ordered_datastore_name: params.ordered_datastore_name.clone(),
+        scope: params.scope.clone(),
+        max_page_size: params.max_page_size.clone(),
+        page_token: params.page_token.clone(),
+        order_by: params.order_by.clone(),
+        filter: params.filter.clone(),
     }
 }
 
@@ -107,124 +824,261 @@ fn build_url(endpoint: &str, universe_id: UniverseId, scope: Option<&str>) -> St
     }
 }
 
-/// List entries of an OrderedDataStore.
-pub async fn list_entries(
-    params: &OrderedListEntriesParams,
-) -> Result<OrderedListEntriesResponse, Error> {
-    let client = reqwest::Client::new();
-    let url = build_url("/entries", params.universe_id, params.scope.as_deref());
-    let mut query: QueryString = vec![];
-    if let Some(max_page_size) = &params.max_page_size {
-        query.push(("max_page_size", max_page_size.to_string()));
-    }
-    if let Some(page_token) = &params.page_token {
-        query.push(("page_token", page_token.to_string()));
-    }
-    if let Some(order_by) = &params.order_by {
-        query.push(("order_by", order_by.to_string()));
-    }
-    if let Some(filter) = &params.filter {
-        query.push(("filter", filter.to_string()));
-    }
-    let res = client
-        .get(url)
-        .header("x-api-key", &params.api_key)
-        .query(&query)
-        .send()
-        .await?;
-    handle_res::<OrderedListEntriesResponse>(res).await
-}
-
-/// Add a new entry to an OrderedDataStore.
-pub async fn create_entry(params: &OrderedCreateEntryParams) -> Result<OrderedEntry, Error> {
-    let client = reqwest::Client::new();
-    let url = build_url("/entries", params.universe_id, params.scope.as_deref());
-    let query: QueryString = vec![("id", params.id.to_string())];
-    let body_json = json!({
-        "value": &params.value,
-    });
-    let body = serde_json::to_string(&body_json)?;
-    let res = client
-        .post(url)
-        .header("x-api-key", &params.api_key)
-        .query(&query)
-        .body(body)
-        .send()
-        .await?;
-    handle_res::<OrderedEntry>(res).await
-}
-
-pub async fn get_entry(params: &OrderedEntryParams) -> Result<OrderedEntry, Error> {
-    let client = reqwest::Client::new();
-    let url = build_url(
-        format!("/entries/{entry}", entry = params.id).as_str(),
-        params.universe_id,
-        params.scope.as_deref(),
-    );
-    let res = client
-        .get(url)
-        .header("x-api-key", &params.api_key)
-        .send()
-        .await?;
-    handle_res::<OrderedEntry>(res).await
-}
-
-pub async fn delete_entry(params: &OrderedEntryParams) -> Result<(), Error> {
-    let client = reqwest::Client::new();
-    let url = build_url(
-        format!("/entries/{entry}", entry = params.id).as_str(),
-        params.universe_id,
-        params.scope.as_deref(),
-    );
-    let res = client
-        .delete(url)
-        .header("x-api-key", &params.api_key)
-        .send()
-        .await?;
-    handle_res_ok(res).await
-}
-
-pub async fn update_entry(params: &OrderedUpdateEntryParams) -> Result<OrderedEntry, Error> {
-    let client = reqwest::Client::new();
-    let url = build_url(
-        format!("/entries/{entry}", entry = params.id).as_str(),
-        params.universe_id,
-        params.scope.as_deref(),
-    );
-    let mut query: QueryString = vec![];
-    if let Some(allow_missing) = &params.allow_missing {
-        query.push(("allow_missing", allow_missing.to_string()));
-    }
-    let body_json = json!({
-        "value": &params.value,
-    });
-    let body = serde_json::to_string(&body_json)?;
-    let res = client
-        .patch(url)
-        .header("x-api-key", &params.api_key)
-        .body(body)
-        .query(&query)
-        .send()
-        .await?;
-    handle_res::<OrderedEntry>(res).await
-}
-
-pub async fn increment_entry(params: &OrderedIncrementEntryParams) -> Result<OrderedEntry, Error> {
-    let client = reqwest::Client::new();
-    let url = build_url(
-        format!("/entries/{entry}:increment", entry = params.id).as_str(),
-        params.universe_id,
-        params.scope.as_deref(),
-    );
-    let body_json = json!({
-        "amount": &params.increment,
-    });
-    let body = serde_json::to_string(&body_json)?;
-    let res = client
-        .patch(url)
-        .header("x-api-key", &params.api_key)
-        .body(body)
-        .send()
-        .await?;
-    handle_res::<OrderedEntry>(res).await
+#[cfg(test)]
+mod page_cursor_tests {
+    use super::*;
+
+    fn entry(id: &str) -> OrderedEntry {
+        OrderedEntry {
+            path: format!("path/{id}"),
+            id: id.to_string(),
+            value: 0.0,
+        }
+    }
+
+    #[test]
+    fn a_page_with_no_next_token_marks_the_cursor_exhausted() {
+        let mut cursor = PageCursor::new();
+        let token = cursor.apply(OrderedListEntriesResponse {
+            entries: vec![entry("a"), entry("b")],
+            next_page_token: None,
+        });
+        assert_eq!(token, None);
+        assert!(cursor.exhausted);
+        assert_eq!(
+            cursor.page.map(|e| e.id).collect::<Vec<_>>(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn a_page_with_a_next_token_keeps_the_cursor_open() {
+        let mut cursor = PageCursor::new();
+        let token = cursor.apply(OrderedListEntriesResponse {
+            entries: vec![entry("a")],
+            next_page_token: Some("page-2".to_string()),
+        });
+        assert_eq!(token, Some("page-2".to_string()));
+        assert!(!cursor.exhausted);
+    }
+
+    #[test]
+    fn following_tokens_across_pages_terminates_once_a_page_has_no_token() {
+        let mut cursor = PageCursor::new();
+        let mut next_token = cursor.apply(OrderedListEntriesResponse {
+            entries: vec![entry("a")],
+            next_page_token: Some("page-2".to_string()),
+        });
+        assert_eq!(next_token, Some("page-2".to_string()));
+        assert!(!cursor.exhausted);
+
+        next_token = cursor.apply(OrderedListEntriesResponse {
+            entries: vec![entry("b")],
+            next_page_token: None,
+        });
+        assert_eq!(next_token, None);
+        assert!(cursor.exhausted);
+    }
+}
+
+#[cfg(test)]
+mod classify_error_tests {
+    use super::*;
+
+    fn response(status: StatusCode, body: &str) -> Response {
+        let http_response = http::Response::builder()
+            .status(status)
+            .body(body.to_string())
+            .unwrap();
+        Response::from(http_response)
+    }
+
+    #[tokio::test]
+    async fn maps_known_statuses_to_their_variant() {
+        assert!(matches!(
+            classify_error(response(StatusCode::NOT_FOUND, "{}")).await,
+            Ok(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            classify_error(response(StatusCode::TOO_MANY_REQUESTS, "{}")).await,
+            Ok(Error::RateLimited { .. })
+        ));
+        assert!(matches!(
+            classify_error(response(StatusCode::BAD_REQUEST, "{}")).await,
+            Ok(Error::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            classify_error(response(StatusCode::UNAUTHORIZED, "{}")).await,
+            Ok(Error::Unauthorized(_))
+        ));
+        assert!(matches!(
+            classify_error(response(StatusCode::FORBIDDEN, "{}")).await,
+            Ok(Error::Unauthorized(_))
+        ));
+        assert!(matches!(
+            classify_error(response(StatusCode::SERVICE_UNAVAILABLE, "{}")).await,
+            Ok(Error::ServiceUnavailable(_))
+        ));
+        assert!(matches!(
+            classify_error(response(StatusCode::CONFLICT, "{}")).await,
+            Ok(Error::DataStoreError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn a_non_json_body_still_classifies_by_status() {
+        let res = response(StatusCode::BAD_GATEWAY, "<html>502 Bad Gateway</html>");
+        let err = classify_error(res)
+            .await
+            .expect("status-based classification must not fail on a non-JSON body");
+        assert!(matches!(err, Error::ServiceUnavailable(_)));
+    }
+
+    #[tokio::test]
+    async fn an_empty_body_still_classifies_by_status() {
+        let res = response(StatusCode::NOT_FOUND, "");
+        let err = classify_error(res)
+            .await
+            .expect("status-based classification must not fail on an empty body");
+        assert!(matches!(err, Error::NotFound(_)));
+    }
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn expired_entries_are_treated_as_absent() {
+        let cache = TtlCache::new(CacheConfig {
+            ttl: Duration::from_millis(10),
+            capacity: 10,
+        });
+        cache.insert("a", 1);
+        assert_eq!(cache.get(&"a"), Some(1));
+        thread::sleep(Duration::from_millis(30));
+        assert_eq!(cache.get(&"a"), None);
+    }
+
+    #[test]
+    fn capacity_evicts_the_oldest_expiring_entry_to_make_room() {
+        let cache = TtlCache::new(CacheConfig {
+            ttl: Duration::from_secs(60),
+            capacity: 2,
+        });
+        cache.insert("a", 1);
+        thread::sleep(Duration::from_millis(5));
+        cache.insert("b", 2);
+        // Inserting past capacity should evict "a", whose expiry is earliest.
+        cache.insert("c", 3);
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(2));
+        assert_eq!(cache.get(&"c"), Some(3));
+    }
+
+    #[test]
+    fn invalidate_removes_a_single_key() {
+        let cache = TtlCache::new(CacheConfig::default());
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.invalidate(&"a");
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(2));
+    }
+
+    #[test]
+    fn invalidate_matching_removes_every_matching_key() {
+        let cache = TtlCache::new(CacheConfig::default());
+        cache.insert(("store-a", 1), "x");
+        cache.insert(("store-a", 2), "y");
+        cache.insert(("store-b", 1), "z");
+        cache.invalidate_matching(|(store, _)| *store == "store-a");
+        assert_eq!(cache.get(&("store-a", 1)), None);
+        assert_eq!(cache.get(&("store-a", 2)), None);
+        assert_eq!(cache.get(&("store-b", 1)), Some("z"));
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_never_exceeds_max_delay() {
+        let retry = RetryConfig {
+            max_retries: 5,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(1),
+        };
+        for attempt in 0..10 {
+            let delay = backoff_delay(&retry, attempt);
+            assert!(
+                delay <= retry.max_delay,
+                "attempt {attempt} produced {delay:?}, exceeding max_delay {:?}",
+                retry.max_delay
+            );
+        }
+    }
+
+    #[test]
+    fn backoff_delay_grows_exponentially_before_the_cap() {
+        let retry = RetryConfig {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(60),
+        };
+        // Jitter only adds up to `base_delay`, so the floor below it is
+        // still strictly increasing between these two attempts.
+        let floor = |attempt: u32| retry.base_delay * 2u32.pow(attempt);
+        assert!(backoff_delay(&retry, 3) >= floor(3));
+        assert!(floor(3) > floor(0));
+    }
+
+    #[test]
+    fn safe_idempotency_retries_rate_limit_and_any_server_error() {
+        assert!(Idempotency::Safe.should_retry(StatusCode::TOO_MANY_REQUESTS));
+        assert!(Idempotency::Safe.should_retry(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(Idempotency::Safe.should_retry(StatusCode::BAD_GATEWAY));
+        assert!(!Idempotency::Safe.should_retry(StatusCode::BAD_REQUEST));
+        assert!(!Idempotency::Safe.should_retry(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn unsafe_idempotency_only_retries_rate_limit_and_503() {
+        assert!(Idempotency::Unsafe.should_retry(StatusCode::TOO_MANY_REQUESTS));
+        assert!(Idempotency::Unsafe.should_retry(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!Idempotency::Unsafe.should_retry(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!Idempotency::Unsafe.should_retry(StatusCode::BAD_GATEWAY));
+    }
+
+    #[test]
+    fn retry_after_header_takes_priority_over_ratelimit_reset() {
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "5".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "9999999999".parse().unwrap());
+        assert_eq!(
+            retry_after_from_headers(&headers),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn ratelimit_reset_is_converted_from_absolute_epoch_to_a_relative_delay() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ratelimit-reset",
+            (now + 30).to_string().parse().unwrap(),
+        );
+        let delay = retry_after_from_headers(&headers).expect("header should parse");
+        // Allow slack for the time elapsed between computing `now` and the
+        // call under test.
+        assert!(delay <= Duration::from_secs(30));
+        assert!(delay >= Duration::from_secs(25));
+    }
 }